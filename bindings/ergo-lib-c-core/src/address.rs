@@ -86,6 +86,89 @@ pub unsafe fn address_type_prefix(address: ConstAddressPtr) -> Result<AddressTyp
     Ok(address.0.address_type_prefix().into())
 }
 
+/// Detect the network and address type of a base58 address without committing
+/// to a network up front.
+///
+/// Decodes the base58 head byte, validates the checksum and reports both the
+/// [`NetworkPrefix`] and the [`AddressTypePrefix`] encoded in it. This gives
+/// callers a single entry point for untrusted address input instead of trying
+/// `address_from_mainnet`/`address_from_testnet` in turn.
+pub unsafe fn address_detect_network(
+    address_str: &str,
+    out_network: *mut NetworkPrefix,
+    out_type: *mut AddressTypePrefix,
+) -> Result<(), Error> {
+    let out_network = mut_ptr_as_mut(out_network, "out_network")?;
+    let out_type = mut_ptr_as_mut(out_type, "out_type")?;
+    // Parsing (unchecked against a network) still verifies the checksum and the
+    // structural prefix, so a malformed string is rejected here.
+    addr::AddressEncoder::unchecked_parse_address_from_str(address_str).map_err(Error::misc)?;
+
+    let head = head_byte(address_str)?;
+    *out_network = network_from_head(head)?;
+    *out_type = type_from_head(head)?;
+    Ok(())
+}
+
+/// Re-encode an address for a different network, reusing the same
+/// script/pubkey.
+pub unsafe fn address_reencode(
+    address: ConstAddressPtr,
+    target_network: NetworkPrefix,
+) -> Result<String, Error> {
+    let address = const_ptr_as_ref(address, "address")?;
+    Ok(addr::AddressEncoder::encode_address_as_string(
+        addr::NetworkPrefix::from(target_network),
+        &address.0,
+    ))
+}
+
+/// Check that a base58 address parses, belongs to `expected_network` and is of
+/// `expected_type`. Returns `false` on any mismatch rather than an error.
+pub unsafe fn address_validate(
+    address_str: &str,
+    expected_network: NetworkPrefix,
+    expected_type: AddressTypePrefix,
+) -> Result<bool, Error> {
+    let encoder = addr::AddressEncoder::new(addr::NetworkPrefix::from(expected_network));
+    let address = match encoder.parse_address_from_str(address_str) {
+        Ok(address) => address,
+        Err(_) => return Ok(false),
+    };
+    let expected: addr::AddressTypePrefix = expected_type.into();
+    Ok(address.address_type_prefix() == expected)
+}
+
+/// Decode the leading byte of a base58 address (network + type prefix).
+fn head_byte(address_str: &str) -> Result<u8, Error> {
+    let bytes = bs58::decode(address_str)
+        .into_vec()
+        .map_err(Error::misc)?;
+    bytes
+        .first()
+        .copied()
+        .ok_or_else(|| Error::misc("empty address"))
+}
+
+/// The network prefix is the high part of the head byte (`0` or `16`).
+fn network_from_head(head: u8) -> Result<NetworkPrefix, Error> {
+    match head & 0xf0 {
+        0 => Ok(NetworkPrefix::Mainnet),
+        16 => Ok(NetworkPrefix::Testnet),
+        _ => Err(Error::misc("unknown network prefix")),
+    }
+}
+
+/// The address type prefix is the low nibble of the head byte (`1`–`3`).
+fn type_from_head(head: u8) -> Result<AddressTypePrefix, Error> {
+    match head & 0x0f {
+        1 => Ok(AddressTypePrefix::P2Pk),
+        2 => Ok(AddressTypePrefix::Pay2Sh),
+        3 => Ok(AddressTypePrefix::Pay2S),
+        _ => Err(Error::misc("unknown address type prefix")),
+    }
+}
+
 /// Create address from ErgoTree
 pub unsafe fn address_from_ergo_tree(
     ergo_tree_ptr: crate::ergo_tree::ConstErgoTreePtr,
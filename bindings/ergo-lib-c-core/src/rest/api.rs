@@ -5,3 +5,4 @@ pub mod node;
 pub mod node_async;
 pub mod request_handle;
 pub mod runtime;
+pub mod subscription;
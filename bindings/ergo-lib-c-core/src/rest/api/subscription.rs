@@ -0,0 +1,181 @@
+//! Streaming subscriptions on top of the async node REST client.
+//!
+//! The plain [`node_async`] calls are request/response only; to *follow* the
+//! chain a caller otherwise has to loop over `request_handle` by hand. The
+//! helpers here turn that polling into push streams: [`subscribe_blocks`] emits
+//! every newly applied [`FullBlock`] in height order (re-emitting from the fork
+//! point on a reorg) and [`subscribe_mempool`] emits every newly seen
+//! unconfirmed [`Transaction`]. Both poll the node on a configurable interval
+//! with exponential backoff on failure. The polling runs inside the stream
+//! itself; no background task is spawned, so the node is only polled while the
+//! stream is being polled.
+//!
+//! [`node_async`]: super::node_async
+use std::collections::HashSet;
+use std::time::Duration;
+
+use async_stream::stream;
+use futures::Stream;
+
+use ergo_lib::chain::block::FullBlock;
+use ergo_lib::chain::transaction::Transaction;
+use ergo_lib::ergo_chain_types::BlockId;
+use ergo_lib::ergo_rest::api::node::NodeConf;
+use ergo_lib::ergo_rest::NodeError;
+use ergo_lib::ergotree_ir::chain::tx_id::TxId;
+
+/// How often, and how aggressively, a subscription polls the node.
+#[derive(Debug, Clone, Copy)]
+pub struct SubscriptionConf {
+    /// Delay between successive polls while healthy.
+    pub poll_interval: Duration,
+    /// Upper bound for the exponential backoff applied after an error.
+    pub max_backoff: Duration,
+}
+
+impl Default for SubscriptionConf {
+    fn default() -> Self {
+        SubscriptionConf {
+            poll_interval: Duration::from_secs(5),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Follow the chain from `from_height`, emitting each newly applied block in
+/// order. On a reorg (the id at an already-seen height changes) the stream
+/// rewinds to the fork point and re-emits the new blocks.
+pub fn subscribe_blocks(
+    node: NodeConf,
+    from_height: u32,
+    conf: SubscriptionConf,
+) -> impl Stream<Item = Result<FullBlock, NodeError>> {
+    stream! {
+        // Block ids we have already emitted, indexed by height - `from_height`.
+        let mut seen: Vec<BlockId> = Vec::new();
+        let mut backoff = conf.poll_interval;
+        loop {
+            match poll_blocks(&node, from_height, &mut seen).await {
+                Ok(new_blocks) => {
+                    backoff = conf.poll_interval;
+                    for (height_offset, block) in new_blocks {
+                        // Truncate on reorg so re-applied heights overwrite.
+                        seen.truncate(height_offset);
+                        seen.push(block.header.id.clone());
+                        yield Ok(block);
+                    }
+                    sleep(conf.poll_interval).await;
+                }
+                Err(err) => {
+                    // Surface the error but keep the subscription alive: back
+                    // off, then retry on the next iteration.
+                    yield Err(err);
+                    sleep(backoff).await;
+                    backoff = (backoff * 2).min(conf.max_backoff);
+                }
+            }
+        }
+    }
+}
+
+/// Watch the mempool, emitting each transaction as it first appears.
+pub fn subscribe_mempool(
+    node: NodeConf,
+    conf: SubscriptionConf,
+) -> impl Stream<Item = Result<Transaction, NodeError>> {
+    stream! {
+        let mut seen: HashSet<TxId> = HashSet::new();
+        let mut backoff = conf.poll_interval;
+        loop {
+            match get_unconfirmed(&node).await {
+                Ok(txs) => {
+                    backoff = conf.poll_interval;
+                    // Drop ids that left the mempool (mined or expired) so the
+                    // set does not grow without bound.
+                    let current: HashSet<TxId> = txs.iter().map(|tx| tx.id()).collect();
+                    seen.retain(|id| current.contains(id));
+                    for tx in txs {
+                        if seen.insert(tx.id()) {
+                            yield Ok(tx);
+                        }
+                    }
+                    sleep(conf.poll_interval).await;
+                }
+                Err(err) => {
+                    // Keep polling after a transient failure rather than ending
+                    // the stream on the first error.
+                    yield Err(err);
+                    sleep(backoff).await;
+                    backoff = (backoff * 2).min(conf.max_backoff);
+                }
+            }
+        }
+    }
+}
+
+/// Fetch the current best height and return any blocks past the ones already
+/// emitted, each paired with its offset from `from_height`. The offset lets the
+/// caller detect and rewind across reorgs.
+///
+/// Steady-state polling only touches the newly applied heights: `seen` records
+/// every height already emitted, so the scan resumes from its tip instead of
+/// re-requesting the whole chain each tick. A reorg is detected by re-checking
+/// the most recently emitted height; mismatched heights are dropped from `seen`
+/// so they are re-fetched and re-emitted from the fork point.
+async fn poll_blocks(
+    node: &NodeConf,
+    from_height: u32,
+    seen: &mut Vec<BlockId>,
+) -> Result<Vec<(usize, FullBlock)>, NodeError> {
+    let info = ergo_lib::ergo_rest::api::node::get_info(node.clone()).await?;
+    let best_height = info.full_height;
+
+    // Rewind across a reorg (or a rolled-back tip): drop trailing heights whose
+    // main-chain id no longer matches what we emitted.
+    while let Some(last) = seen.last() {
+        let height = from_height + (seen.len() as u32 - 1);
+        if height > best_height {
+            seen.pop();
+            continue;
+        }
+        let ids = ergo_lib::ergo_rest::api::node::get_block_ids_at_height(node.clone(), height).await?;
+        match ids.into_iter().next() {
+            Some(id) if &id == last => break,
+            _ => {
+                seen.pop();
+            }
+        }
+    }
+
+    // Resume from the first height we have not yet emitted.
+    let mut out = Vec::new();
+    let mut height = from_height + seen.len() as u32;
+    while height <= best_height {
+        let offset = (height - from_height) as usize;
+        let ids = ergo_lib::ergo_rest::api::node::get_block_ids_at_height(node.clone(), height).await?;
+        // The node returns the main-chain id first.
+        let id = match ids.into_iter().next() {
+            Some(id) => id,
+            None => break,
+        };
+        let block =
+            ergo_lib::ergo_rest::api::node::get_block_by_id(node.clone(), id).await?;
+        out.push((offset, block));
+        height += 1;
+    }
+    Ok(out)
+}
+
+async fn get_unconfirmed(node: &NodeConf) -> Result<Vec<Transaction>, NodeError> {
+    ergo_lib::ergo_rest::api::node::get_unconfirmed_transactions(node.clone()).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn sleep(dur: Duration) {
+    tokio::time::sleep(dur).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn sleep(dur: Duration) {
+    gloo_timers::future::sleep(dur).await;
+}
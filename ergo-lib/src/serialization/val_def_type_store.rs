@@ -1,8 +1,15 @@
 use std::collections::HashMap;
 
-use crate::ast::val_def::ValId;
-use crate::types::stype::SType;
+use ergotree_ir::mir::bin_op::{BinOp, BinOpKind, RelationOp};
+use ergotree_ir::mir::expr::Expr;
+use ergotree_ir::mir::val_def::ValId;
+use ergotree_ir::types::stype::SType;
 
+/// Resolved types for every `val` (and lambda) binding in a script.
+///
+/// The map is filled by [`TypeInference`] once constraint solving has
+/// succeeded; [`get`](Self::get) then serves the concrete [`SType`] for a given
+/// [`ValId`].
 pub struct ValDefTypeStore(HashMap<ValId, SType>);
 
 impl ValDefTypeStore {
@@ -12,12 +19,22 @@ impl ValDefTypeStore {
 
     pub fn insert(&mut self, id: ValId, tpe: SType) {
         self.0.insert(id, tpe);
-        dbg!(&self.0);
     }
 
     pub fn get(&self, id: &ValId) -> Option<&SType> {
         self.0.get(id)
     }
+
+    /// Populate this store by inferring the type of every `val` binding
+    /// reachable from `expr`.
+    ///
+    /// This back-fills the store for an `Expr`: the `ValDef` types are resolved
+    /// here so later `ValUse` lookups by [`ValId`] succeed. Inference writes
+    /// into `self`, so types already added via [`insert`](Self::insert) for
+    /// other ids are kept; an id that inference resolves overwrites its entry.
+    pub fn populate_from(&mut self, expr: &Expr) -> Result<(), TypeError> {
+        TypeInference::new().infer_into(self, expr)
+    }
 }
 
 impl Default for ValDefTypeStore {
@@ -25,3 +42,409 @@ impl Default for ValDefTypeStore {
         ValDefTypeStore::new()
     }
 }
+
+/// A type that mismatched during unification.
+///
+/// A type with no leaf [`SType`] (a function or a still-free variable) is
+/// reported as `SBoolean` in either field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeError {
+    /// One of the two types that failed to unify, or `SInt` when an
+    /// arithmetic operand turned out not to be numeric.
+    pub expected: SType,
+    /// The other type that failed to unify (the non-numeric operand type in
+    /// the arithmetic case).
+    pub found: SType,
+}
+
+impl std::fmt::Display for TypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "type mismatch: expected {:?}, found {:?}",
+            self.expected, self.found
+        )
+    }
+}
+
+/// An inference-time type. Unlike [`SType`] it admits unification variables and
+/// an explicit function arrow, both of which are eliminated once solving is
+/// done and the result is lowered back to an [`SType`].
+#[derive(Debug, Clone, PartialEq)]
+enum Ty {
+    Var(u32),
+    Boolean,
+    Byte,
+    Int,
+    Long,
+    BigInt,
+    Fun(Box<Ty>, Box<Ty>),
+    /// A concrete [`SType`] we do not further constrain; it unifies only with an
+    /// equal `Opaque`.
+    Opaque(SType),
+}
+
+impl Ty {
+    fn from_stype(tpe: &SType) -> Ty {
+        match tpe {
+            SType::SBoolean => Ty::Boolean,
+            SType::SByte => Ty::Byte,
+            SType::SInt => Ty::Int,
+            SType::SLong => Ty::Long,
+            SType::SBigInt => Ty::BigInt,
+            // Types the numeric/boolean machinery does not constrain are carried
+            // verbatim as opaque leaves that only unify with themselves.
+            other => Ty::Opaque(other.clone()),
+        }
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(self, Ty::Byte | Ty::Int | Ty::Long | Ty::BigInt)
+    }
+}
+
+/// Algorithm-W–style inference over the parsed AST.
+///
+/// Fresh type variables are introduced for unannotated bindings, expressions
+/// generate equality constraints which are solved by a union-find
+/// substitution, and the resolved concrete type of every binding is written
+/// into a [`ValDefTypeStore`].
+pub struct TypeInference {
+    /// `subst[v]` is the representative/binding for variable `v` (union-find).
+    subst: Vec<Option<Ty>>,
+    /// The inference variable assigned to each binding in scope.
+    vars: HashMap<ValId, Ty>,
+    /// Variables that must resolve to a numeric type (seeded by `+`, `*`, …).
+    numeric: Vec<Ty>,
+}
+
+impl TypeInference {
+    pub fn new() -> Self {
+        Self {
+            subst: Vec::new(),
+            vars: HashMap::new(),
+            numeric: Vec::new(),
+        }
+    }
+
+    /// Infer the types of every binding reachable from `expr`, returning a
+    /// freshly populated store on success.
+    pub fn infer(self, expr: &Expr) -> Result<ValDefTypeStore, TypeError> {
+        let mut store = ValDefTypeStore::new();
+        self.infer_into(&mut store, expr)?;
+        Ok(store)
+    }
+
+    /// Infer binding types for `expr` and write the resolved concrete types
+    /// into `store`. Existing entries for other ids are left in place; an id
+    /// resolved here overwrites its existing entry.
+    pub fn infer_into(mut self, store: &mut ValDefTypeStore, expr: &Expr) -> Result<(), TypeError> {
+        self.infer_expr(expr)?;
+        self.check_numeric()?;
+
+        let vars: Vec<(ValId, Ty)> = self.vars.iter().map(|(k, v)| (*k, v.clone())).collect();
+        for (id, ty) in vars {
+            let resolved = self.resolve(&ty);
+            // Leave genuinely unconstrained bindings out of the store rather
+            // than inventing a concrete type for them.
+            if let Some(stype) = self.lower(&resolved)? {
+                store.insert(id, stype);
+            }
+        }
+        Ok(())
+    }
+
+    fn fresh(&mut self) -> Ty {
+        let id = self.subst.len() as u32;
+        self.subst.push(None);
+        Ty::Var(id)
+    }
+
+    /// Follow the substitution chain until a non-bound variable or a concrete
+    /// type is reached (the find half of union-find).
+    fn resolve(&self, ty: &Ty) -> Ty {
+        match ty {
+            Ty::Var(v) => match self.subst.get(*v as usize).and_then(|s| s.clone()) {
+                Some(bound) => self.resolve(&bound),
+                None => ty.clone(),
+            },
+            Ty::Fun(a, b) => Ty::Fun(Box::new(self.resolve(a)), Box::new(self.resolve(b))),
+            other => other.clone(),
+        }
+    }
+
+    /// Unify two types, recording the binding or raising a [`TypeError`].
+    fn unify(&mut self, a: &Ty, b: &Ty) -> Result<(), TypeError> {
+        let a = self.resolve(a);
+        let b = self.resolve(b);
+        match (&a, &b) {
+            _ if a == b => Ok(()),
+            (Ty::Var(v), other) | (other, Ty::Var(v)) => {
+                if self.occurs(*v, other) {
+                    return Err(self.mismatch(&a, &b));
+                }
+                self.subst[*v as usize] = Some(other.clone());
+                Ok(())
+            }
+            (Ty::Fun(a1, a2), Ty::Fun(b1, b2)) => {
+                self.unify(a1, b1)?;
+                self.unify(a2, b2)
+            }
+            _ => Err(self.mismatch(&a, &b)),
+        }
+    }
+
+    /// The occurs-check that rejects infinite types.
+    fn occurs(&self, v: u32, ty: &Ty) -> bool {
+        match self.resolve(ty) {
+            Ty::Var(w) => v == w,
+            Ty::Fun(a, b) => self.occurs(v, &a) || self.occurs(v, &b),
+            _ => false,
+        }
+    }
+
+    fn mismatch(&self, a: &Ty, b: &Ty) -> TypeError {
+        TypeError {
+            expected: self.lower(a).ok().flatten().unwrap_or(SType::SBoolean),
+            found: self.lower(b).ok().flatten().unwrap_or(SType::SBoolean),
+        }
+    }
+
+    fn check_numeric(&self) -> Result<(), TypeError> {
+        for ty in &self.numeric {
+            let r = self.resolve(ty);
+            // An unresolved variable is not checked (its binding is later left
+            // out of the store); only a resolved non-numeric type is an error.
+            if !matches!(r, Ty::Var(_)) && !r.is_numeric() {
+                return Err(TypeError {
+                    expected: SType::SInt,
+                    found: self.lower(&r).ok().flatten().unwrap_or(SType::SBoolean),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Lower a solved inference type to the leaf [`SType`] recorded in the
+    /// store. A still-free variable or a function arrow has no leaf `SType` to
+    /// record and yields `None`, so such bindings are simply left out of the
+    /// store rather than aborting the whole pass.
+    fn lower(&self, ty: &Ty) -> Result<Option<SType>, TypeError> {
+        match self.resolve(ty) {
+            Ty::Boolean => Ok(Some(SType::SBoolean)),
+            Ty::Byte => Ok(Some(SType::SByte)),
+            Ty::Int => Ok(Some(SType::SInt)),
+            Ty::Long => Ok(Some(SType::SLong)),
+            Ty::BigInt => Ok(Some(SType::SBigInt)),
+            Ty::Opaque(tpe) => Ok(Some(tpe)),
+            Ty::Var(_) | Ty::Fun(_, _) => Ok(None),
+        }
+    }
+
+    /// Walk an expression, returning its inferred type and threading
+    /// constraints through the solver.
+    fn infer_expr(&mut self, expr: &Expr) -> Result<Ty, TypeError> {
+        match expr {
+            Expr::Const(c) => Ok(Ty::from_stype(&c.tpe)),
+            Expr::ValUse(v) => Ok(self
+                .vars
+                .get(&v.val_id)
+                .cloned()
+                .unwrap_or_else(|| Ty::from_stype(&v.tpe))),
+            Expr::ValDef(val_def) => {
+                let rhs = self.infer_expr(&val_def.rhs)?;
+                self.vars.insert(val_def.id, rhs.clone());
+                Ok(rhs)
+            }
+            Expr::BlockValue(block) => {
+                for item in block.items.iter() {
+                    self.infer_expr(item)?;
+                }
+                self.infer_expr(&block.result)
+            }
+            Expr::BinOp(op) => self.infer_binop(op),
+            Expr::If(if_expr) => {
+                let cond = self.infer_expr(&if_expr.condition)?;
+                self.unify(&cond, &Ty::Boolean)?;
+                let t = self.infer_expr(&if_expr.true_branch)?;
+                let f = self.infer_expr(&if_expr.false_branch)?;
+                self.unify(&t, &f)?;
+                Ok(self.resolve(&t))
+            }
+            Expr::Apply(apply) => {
+                let func = self.infer_expr(&apply.func)?;
+                let ret = self.fresh();
+                // Build the expected arrow from the actual argument types and
+                // unify it against the callee.
+                let mut expected = ret.clone();
+                for arg in apply.args.iter().rev() {
+                    let arg_ty = self.infer_expr(arg)?;
+                    expected = Ty::Fun(Box::new(arg_ty), Box::new(expected));
+                }
+                self.unify(&func, &expected)?;
+                Ok(self.resolve(&ret))
+            }
+            Expr::FuncValue(func) => {
+                let mut params = Vec::new();
+                for arg in func.args().iter() {
+                    let param = self.fresh();
+                    // The declared parameter type seeds the variable.
+                    self.unify(&param, &Ty::from_stype(&arg.tpe))?;
+                    self.vars.insert(arg.idx, param.clone());
+                    params.push(param);
+                }
+                let body = self.infer_expr(func.body())?;
+                let mut ty = body;
+                for param in params.into_iter().rev() {
+                    ty = Ty::Fun(Box::new(param), Box::new(ty));
+                }
+                Ok(ty)
+            }
+            // Expressions the pass does not model contribute a fresh type
+            // variable so inference can continue around them.
+            _ => Ok(self.fresh()),
+        }
+    }
+
+    fn infer_binop(&mut self, op: &BinOp) -> Result<Ty, TypeError> {
+        let lhs = self.infer_expr(&op.left)?;
+        let rhs = self.infer_expr(&op.right)?;
+        match op.kind {
+            // Arithmetic (and bit ops): both operands share one numeric type,
+            // which is also the result type.
+            BinOpKind::Arith(_) => {
+                self.unify(&lhs, &rhs)?;
+                self.numeric.push(lhs.clone());
+                Ok(self.resolve(&lhs))
+            }
+            // Logical connectives require booleans and produce a boolean.
+            BinOpKind::Relation(RelationOp::And) | BinOpKind::Relation(RelationOp::Or) => {
+                self.unify(&lhs, &Ty::Boolean)?;
+                self.unify(&rhs, &Ty::Boolean)?;
+                Ok(Ty::Boolean)
+            }
+            // Comparisons require matching operand types and yield a boolean.
+            BinOpKind::Relation(_) => {
+                self.unify(&lhs, &rhs)?;
+                Ok(Ty::Boolean)
+            }
+        }
+    }
+}
+
+impl Default for TypeInference {
+    fn default() -> Self {
+        TypeInference::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ergotree_ir::mir::bin_op::{ArithOp, BinOp, BinOpKind, RelationOp};
+    use ergotree_ir::mir::block::BlockValue;
+    use ergotree_ir::mir::if_op::If;
+    use ergotree_ir::mir::val_def::ValDef;
+    use ergotree_ir::mir::val_use::ValUse;
+
+    fn int_const() -> Expr {
+        Expr::Const(1i32.into())
+    }
+
+    fn bool_const() -> Expr {
+        Expr::Const(true.into())
+    }
+
+    fn val_use(id: u32, tpe: SType) -> Expr {
+        Expr::ValUse(ValUse {
+            val_id: ValId(id),
+            tpe,
+        })
+    }
+
+    fn bin(kind: BinOpKind, left: Expr, right: Expr) -> Expr {
+        Expr::BinOp(BinOp {
+            kind,
+            left: Box::new(left),
+            right: Box::new(right),
+        })
+    }
+
+    #[test]
+    fn infer_nested_val_chain() {
+        // val a = 1 + 2; val b = a + 3; b
+        let a = Expr::ValDef(ValDef {
+            id: ValId(0),
+            rhs: Box::new(bin(
+                BinOpKind::Arith(ArithOp::Plus),
+                int_const(),
+                int_const(),
+            )),
+        });
+        let b = Expr::ValDef(ValDef {
+            id: ValId(1),
+            rhs: Box::new(bin(
+                BinOpKind::Arith(ArithOp::Plus),
+                val_use(0, SType::SInt),
+                int_const(),
+            )),
+        });
+        let block = Expr::BlockValue(BlockValue {
+            items: vec![a, b],
+            result: Box::new(val_use(1, SType::SInt)),
+        });
+        let store = TypeInference::new().infer(&block).unwrap();
+        assert_eq!(store.get(&ValId(0)), Some(&SType::SInt));
+        assert_eq!(store.get(&ValId(1)), Some(&SType::SInt));
+    }
+
+    #[test]
+    fn populate_from_fills_store_in_place() {
+        // `val a = 1 + 2; a`
+        let a = Expr::ValDef(ValDef {
+            id: ValId(0),
+            rhs: Box::new(bin(
+                BinOpKind::Arith(ArithOp::Plus),
+                int_const(),
+                int_const(),
+            )),
+        });
+        let block = Expr::BlockValue(BlockValue {
+            items: vec![a],
+            result: Box::new(val_use(0, SType::SInt)),
+        });
+        let mut store = ValDefTypeStore::new();
+        store.populate_from(&block).unwrap();
+        assert_eq!(store.get(&ValId(0)), Some(&SType::SInt));
+    }
+
+    #[test]
+    fn reject_mismatched_if_branches() {
+        let if_expr = Expr::If(If {
+            condition: Box::new(bool_const()),
+            true_branch: Box::new(int_const()),
+            false_branch: Box::new(bool_const()),
+        });
+        assert!(TypeInference::new().infer(&if_expr).is_err());
+    }
+
+    #[test]
+    fn comparison_is_boolean() {
+        let cmp = bin(
+            BinOpKind::Relation(RelationOp::LT),
+            int_const(),
+            int_const(),
+        );
+        let v = Expr::ValDef(ValDef {
+            id: ValId(0),
+            rhs: Box::new(cmp),
+        });
+        let store = TypeInference::new()
+            .infer(&Expr::BlockValue(BlockValue {
+                items: vec![v],
+                result: Box::new(bool_const()),
+            }))
+            .unwrap();
+        assert_eq!(store.get(&ValId(0)), Some(&SType::SBoolean));
+    }
+}
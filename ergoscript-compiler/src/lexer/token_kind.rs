@@ -14,6 +14,21 @@ pub enum TokenKind {
     #[token("val")]
     ValKw,
 
+    #[token("if")]
+    IfKw,
+
+    #[token("else")]
+    ElseKw,
+
+    #[token("true")]
+    TrueKw,
+
+    #[token("false")]
+    FalseKw,
+
+    #[token("return")]
+    ReturnKw,
+
     #[regex("[A-Za-z][A-Za-z0-9]*")]
     Ident,
 
@@ -23,6 +38,15 @@ pub enum TokenKind {
     #[regex("[0-9]+L")]
     LongNumber,
 
+    #[regex("[0-9]+BigInt")]
+    BigIntNumber,
+
+    #[regex("0x([0-9a-fA-F][0-9a-fA-F])+")]
+    ByteArray,
+
+    #[regex("\"([^\"\\\\]|\\\\.)*\"")]
+    String,
+
     #[token("+")]
     Plus,
 
@@ -38,9 +62,60 @@ pub enum TokenKind {
     #[token("&&")]
     And,
 
+    #[token("||")]
+    Or,
+
+    #[token("!")]
+    Bang,
+
+    #[token("<")]
+    LessThan,
+
+    #[token(">")]
+    GreaterThan,
+
+    #[token("<=")]
+    LessThanOrEqual,
+
+    #[token(">=")]
+    GreaterThanOrEqual,
+
+    #[token("==")]
+    EqualsEquals,
+
+    #[token("!=")]
+    NotEquals,
+
+    #[token("|")]
+    BitOr,
+
+    #[token("^")]
+    BitXor,
+
+    #[token(">>")]
+    Shr,
+
+    #[token("<<")]
+    Shl,
+
+    #[token(">>>")]
+    UnsignedShr,
+
     #[token("=")]
     Equals,
 
+    #[token("=>")]
+    FatArrow,
+
+    #[token(".")]
+    Dot,
+
+    #[token(",")]
+    Comma,
+
+    #[token(":")]
+    Colon,
+
     #[token("(")]
     LParen,
 
@@ -53,6 +128,12 @@ pub enum TokenKind {
     #[token("}")]
     RBrace,
 
+    #[token("[")]
+    LBracket,
+
+    #[token("]")]
+    RBracket,
+
     #[regex("//.*")]
     Comment,
 
@@ -72,19 +153,46 @@ impl fmt::Display for TokenKind {
             Self::Whitespace => "whitespace",
             Self::FnKw => "‘def’",
             Self::ValKw => "‘val’",
+            Self::IfKw => "‘if’",
+            Self::ElseKw => "‘else’",
+            Self::TrueKw => "‘true’",
+            Self::FalseKw => "‘false’",
+            Self::ReturnKw => "‘return’",
             Self::Ident => "identifier",
             Self::IntNumber => "number",
             Self::LongNumber => "number",
+            Self::BigIntNumber => "number",
+            Self::ByteArray => "byte array",
+            Self::String => "string",
             Self::Plus => "‘+’",
             Self::Minus => "‘-’",
             Self::Star => "‘*’",
             Self::Slash => "‘/’",
             Self::And => "‘&&’",
+            Self::Or => "‘||’",
+            Self::Bang => "‘!’",
+            Self::LessThan => "‘<’",
+            Self::GreaterThan => "‘>’",
+            Self::LessThanOrEqual => "‘<=’",
+            Self::GreaterThanOrEqual => "‘>=’",
+            Self::EqualsEquals => "‘==’",
+            Self::NotEquals => "‘!=’",
+            Self::BitOr => "‘|’",
+            Self::BitXor => "‘^’",
+            Self::Shr => "‘>>’",
+            Self::Shl => "‘<<’",
+            Self::UnsignedShr => "‘>>>’",
             Self::Equals => "‘=’",
+            Self::FatArrow => "‘=>’",
+            Self::Dot => "‘.’",
+            Self::Comma => "‘,’",
+            Self::Colon => "‘:’",
             Self::LParen => "‘(’",
             Self::RParen => "‘)’",
             Self::LBrace => "‘{’",
             Self::RBrace => "‘}’",
+            Self::LBracket => "‘[’",
+            Self::RBracket => "‘]’",
             Self::Comment => "comment",
             Self::Error => "an unrecognized token",
         })
@@ -119,6 +227,31 @@ mod tests {
         check("val", TokenKind::ValKw);
     }
 
+    #[test]
+    fn lex_if_keyword() {
+        check("if", TokenKind::IfKw);
+    }
+
+    #[test]
+    fn lex_else_keyword() {
+        check("else", TokenKind::ElseKw);
+    }
+
+    #[test]
+    fn lex_true_keyword() {
+        check("true", TokenKind::TrueKw);
+    }
+
+    #[test]
+    fn lex_false_keyword() {
+        check("false", TokenKind::FalseKw);
+    }
+
+    #[test]
+    fn lex_return_keyword() {
+        check("return", TokenKind::ReturnKw);
+    }
+
     #[test]
     fn lex_alphabetic_identifier() {
         check("abcd", TokenKind::Ident);
@@ -144,6 +277,31 @@ mod tests {
         check("123456", TokenKind::IntNumber);
     }
 
+    #[test]
+    fn lex_long_number() {
+        check("123456L", TokenKind::LongNumber);
+    }
+
+    #[test]
+    fn lex_big_int_number() {
+        check("123456BigInt", TokenKind::BigIntNumber);
+    }
+
+    #[test]
+    fn lex_byte_array() {
+        check("0xdeadbeef", TokenKind::ByteArray);
+    }
+
+    #[test]
+    fn lex_string() {
+        check("\"foo\"", TokenKind::String);
+    }
+
+    #[test]
+    fn lex_string_with_escape() {
+        check("\"foo\\\"bar\"", TokenKind::String);
+    }
+
     #[test]
     fn lex_plus() {
         check("+", TokenKind::Plus);
@@ -164,11 +322,101 @@ mod tests {
         check("/", TokenKind::Slash);
     }
 
+    #[test]
+    fn lex_and() {
+        check("&&", TokenKind::And);
+    }
+
+    #[test]
+    fn lex_or() {
+        check("||", TokenKind::Or);
+    }
+
+    #[test]
+    fn lex_bang() {
+        check("!", TokenKind::Bang);
+    }
+
+    #[test]
+    fn lex_less_than() {
+        check("<", TokenKind::LessThan);
+    }
+
+    #[test]
+    fn lex_greater_than() {
+        check(">", TokenKind::GreaterThan);
+    }
+
+    #[test]
+    fn lex_less_than_or_equal() {
+        check("<=", TokenKind::LessThanOrEqual);
+    }
+
+    #[test]
+    fn lex_greater_than_or_equal() {
+        check(">=", TokenKind::GreaterThanOrEqual);
+    }
+
+    #[test]
+    fn lex_equals_equals() {
+        check("==", TokenKind::EqualsEquals);
+    }
+
+    #[test]
+    fn lex_not_equals() {
+        check("!=", TokenKind::NotEquals);
+    }
+
+    #[test]
+    fn lex_bit_or() {
+        check("|", TokenKind::BitOr);
+    }
+
+    #[test]
+    fn lex_bit_xor() {
+        check("^", TokenKind::BitXor);
+    }
+
+    #[test]
+    fn lex_shr() {
+        check(">>", TokenKind::Shr);
+    }
+
+    #[test]
+    fn lex_shl() {
+        check("<<", TokenKind::Shl);
+    }
+
+    #[test]
+    fn lex_unsigned_shr() {
+        check(">>>", TokenKind::UnsignedShr);
+    }
+
     #[test]
     fn lex_equals() {
         check("=", TokenKind::Equals);
     }
 
+    #[test]
+    fn lex_fat_arrow() {
+        check("=>", TokenKind::FatArrow);
+    }
+
+    #[test]
+    fn lex_dot() {
+        check(".", TokenKind::Dot);
+    }
+
+    #[test]
+    fn lex_comma() {
+        check(",", TokenKind::Comma);
+    }
+
+    #[test]
+    fn lex_colon() {
+        check(":", TokenKind::Colon);
+    }
+
     #[test]
     fn lex_left_parenthesis() {
         check("(", TokenKind::LParen);
@@ -189,6 +437,16 @@ mod tests {
         check("}", TokenKind::RBrace);
     }
 
+    #[test]
+    fn lex_left_bracket() {
+        check("[", TokenKind::LBracket);
+    }
+
+    #[test]
+    fn lex_right_bracket() {
+        check("]", TokenKind::RBracket);
+    }
+
     #[test]
     fn lex_comment() {
         check("// foo", TokenKind::Comment);
@@ -0,0 +1,6 @@
+//! A compiler frontend for ErgoScript.
+//!
+//! The [`lexer`] splits source into a flat `(TokenKind, text, span)` stream and
+//! the [`parser`] turns that stream into a lossless concrete syntax tree.
+mod lexer;
+pub mod parser;
@@ -0,0 +1,704 @@
+// A hand-written recursive-descent parser sitting on top of the `lexer`.
+//
+// The shape follows the approach described at https://arzg.github.io/lang/:
+// the lexer produces a flat `(TokenKind, text, span)` stream and the parser
+// turns it into a *lossless* concrete syntax tree built with `rowan`. Binary
+// operators are parsed with precedence climbing (a Pratt parser) and errors
+// are recovered from at statement boundaries so that an edit in the middle of
+// a script still yields a usable tree for IDE features.
+use std::fmt;
+use std::ops::Range;
+
+use rowan::{GreenNode, GreenNodeBuilder, Language};
+
+use crate::lexer::{Lexer, TokenKind};
+
+/// Parse `input` into a lossless syntax tree plus any errors encountered.
+///
+/// Parsing never fails outright: on an unexpected token a diagnostic is
+/// recorded, an `Error` node is emitted and the parser recovers by skipping to
+/// the next statement boundary, so the returned [`SyntaxNode`] always covers
+/// the whole input.
+pub fn parse(input: &str) -> (SyntaxNode, Vec<ParseError>) {
+    let tokens: Vec<Token> = Lexer::new(input)
+        .map(|t| Token {
+            kind: t.kind,
+            text: t.text.to_string(),
+            span: t.span(),
+        })
+        .collect();
+    Parser::new(tokens).parse()
+}
+
+/// A token with its source text and byte span, as produced by the lexer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Token {
+    kind: TokenKind,
+    text: String,
+    span: Range<usize>,
+}
+
+/// The language definition required by `rowan`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErgoScriptLang {}
+
+impl Language for ErgoScriptLang {
+    type Kind = SyntaxKind;
+
+    fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+        SyntaxKind::from_raw(raw.0)
+    }
+
+    fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+        rowan::SyntaxKind(kind as u16)
+    }
+}
+
+/// A node in the resulting lossless syntax tree.
+pub type SyntaxNode = rowan::SyntaxNode<ErgoScriptLang>;
+
+/// The kind of a syntax tree node.
+///
+/// The leading variants mirror [`TokenKind`] one-to-one (a token becomes a leaf
+/// of the same kind); the trailing variants are the composite nodes the parser
+/// introduces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u16)]
+pub enum SyntaxKind {
+    // Tokens (must stay in sync with `TokenKind`).
+    Whitespace,
+    FnKw,
+    ValKw,
+    IfKw,
+    ElseKw,
+    TrueKw,
+    FalseKw,
+    ReturnKw,
+    Ident,
+    IntNumber,
+    LongNumber,
+    BigIntNumber,
+    ByteArray,
+    String,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    And,
+    Or,
+    Bang,
+    LessThan,
+    GreaterThan,
+    LessThanOrEqual,
+    GreaterThanOrEqual,
+    EqualsEquals,
+    NotEquals,
+    BitOr,
+    BitXor,
+    Shr,
+    Shl,
+    UnsignedShr,
+    Equals,
+    FatArrow,
+    Dot,
+    Comma,
+    Colon,
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Comment,
+    Error,
+    // Composite nodes.
+    Root,
+    Literal,
+    VariableRef,
+    PrefixExpr,
+    BinaryExpr,
+    ParenExpr,
+    Block,
+    VariableDef,
+    Lambda,
+    ParamList,
+    Param,
+    IfExpr,
+    Call,
+    ArgList,
+}
+
+impl SyntaxKind {
+    fn from_raw(raw: u16) -> Self {
+        // SAFETY: the parser only ever stores `SyntaxKind` discriminants, so
+        // any raw value handed back by `rowan` originated from this enum.
+        assert!(raw <= SyntaxKind::ArgList as u16);
+        unsafe { std::mem::transmute::<u16, SyntaxKind>(raw) }
+    }
+}
+
+impl From<TokenKind> for SyntaxKind {
+    fn from(kind: TokenKind) -> Self {
+        match kind {
+            TokenKind::Whitespace => SyntaxKind::Whitespace,
+            TokenKind::FnKw => SyntaxKind::FnKw,
+            TokenKind::ValKw => SyntaxKind::ValKw,
+            TokenKind::IfKw => SyntaxKind::IfKw,
+            TokenKind::ElseKw => SyntaxKind::ElseKw,
+            TokenKind::TrueKw => SyntaxKind::TrueKw,
+            TokenKind::FalseKw => SyntaxKind::FalseKw,
+            TokenKind::ReturnKw => SyntaxKind::ReturnKw,
+            TokenKind::Ident => SyntaxKind::Ident,
+            TokenKind::IntNumber => SyntaxKind::IntNumber,
+            TokenKind::LongNumber => SyntaxKind::LongNumber,
+            TokenKind::BigIntNumber => SyntaxKind::BigIntNumber,
+            TokenKind::ByteArray => SyntaxKind::ByteArray,
+            TokenKind::String => SyntaxKind::String,
+            TokenKind::Plus => SyntaxKind::Plus,
+            TokenKind::Minus => SyntaxKind::Minus,
+            TokenKind::Star => SyntaxKind::Star,
+            TokenKind::Slash => SyntaxKind::Slash,
+            TokenKind::And => SyntaxKind::And,
+            TokenKind::Or => SyntaxKind::Or,
+            TokenKind::Bang => SyntaxKind::Bang,
+            TokenKind::LessThan => SyntaxKind::LessThan,
+            TokenKind::GreaterThan => SyntaxKind::GreaterThan,
+            TokenKind::LessThanOrEqual => SyntaxKind::LessThanOrEqual,
+            TokenKind::GreaterThanOrEqual => SyntaxKind::GreaterThanOrEqual,
+            TokenKind::EqualsEquals => SyntaxKind::EqualsEquals,
+            TokenKind::NotEquals => SyntaxKind::NotEquals,
+            TokenKind::BitOr => SyntaxKind::BitOr,
+            TokenKind::BitXor => SyntaxKind::BitXor,
+            TokenKind::Shr => SyntaxKind::Shr,
+            TokenKind::Shl => SyntaxKind::Shl,
+            TokenKind::UnsignedShr => SyntaxKind::UnsignedShr,
+            TokenKind::Equals => SyntaxKind::Equals,
+            TokenKind::FatArrow => SyntaxKind::FatArrow,
+            TokenKind::Dot => SyntaxKind::Dot,
+            TokenKind::Comma => SyntaxKind::Comma,
+            TokenKind::Colon => SyntaxKind::Colon,
+            TokenKind::LParen => SyntaxKind::LParen,
+            TokenKind::RParen => SyntaxKind::RParen,
+            TokenKind::LBrace => SyntaxKind::LBrace,
+            TokenKind::RBrace => SyntaxKind::RBrace,
+            TokenKind::LBracket => SyntaxKind::LBracket,
+            TokenKind::RBracket => SyntaxKind::RBracket,
+            TokenKind::Comment => SyntaxKind::Comment,
+            TokenKind::Error => SyntaxKind::Error,
+        }
+    }
+}
+
+/// A recoverable parse error, carrying the byte span at which it occurred, the
+/// token kinds that were expected there and the kind actually found (if any).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Byte range in the source the error refers to.
+    pub span: Range<usize>,
+    /// Token kinds that would have been valid at this position.
+    pub expected: Vec<TokenKind>,
+    /// The token kind actually encountered, or `None` at end of input.
+    pub found: Option<TokenKind>,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error at {}..{}: expected ", self.span.start, self.span.end)?;
+        for (i, kind) in self.expected.iter().enumerate() {
+            if i != 0 {
+                if i == self.expected.len() - 1 {
+                    write!(f, " or ")?;
+                } else {
+                    write!(f, ", ")?;
+                }
+            }
+            write!(f, "{}", kind)?;
+        }
+        if let Some(found) = self.found {
+            write!(f, ", but found {}", found)?;
+        }
+        Ok(())
+    }
+}
+
+/// Left/right binding powers for an infix operator. A higher number binds
+/// tighter; the asymmetry encodes associativity (left-associative operators
+/// have `right > left`).
+fn infix_binding_power(kind: TokenKind) -> Option<(u8, u8)> {
+    Some(match kind {
+        TokenKind::Or => (1, 2),
+        TokenKind::And => (3, 4),
+        TokenKind::BitOr => (5, 6),
+        TokenKind::BitXor => (7, 8),
+        TokenKind::LessThan
+        | TokenKind::GreaterThan
+        | TokenKind::LessThanOrEqual
+        | TokenKind::GreaterThanOrEqual
+        | TokenKind::EqualsEquals
+        | TokenKind::NotEquals => (9, 10),
+        TokenKind::Shl | TokenKind::Shr | TokenKind::UnsignedShr => (11, 12),
+        TokenKind::Plus | TokenKind::Minus => (13, 14),
+        TokenKind::Star | TokenKind::Slash => (15, 16),
+        // `.` (member access) and calls are handled as postfix operators in
+        // `parse_postfix`, which binds tighter than any infix operator here.
+        _ => return None,
+    })
+}
+
+/// Binding power for the prefix operators `-` and `!`.
+fn prefix_binding_power(kind: TokenKind) -> Option<u8> {
+    match kind {
+        // Tighter than every infix operator so `-a * b` is `(-a) * b`.
+        TokenKind::Minus | TokenKind::Bang => Some(17),
+        _ => None,
+    }
+}
+
+/// Tokens at which error recovery stops: the start of a fresh statement or the
+/// end of a block.
+fn is_statement_boundary(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::ValKw | TokenKind::FnKw | TokenKind::RBrace
+    )
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    cursor: usize,
+    builder: GreenNodeBuilder<'static>,
+    errors: Vec<ParseError>,
+}
+
+impl Parser {
+    fn new(tokens: Vec<Token>) -> Self {
+        Self {
+            tokens,
+            cursor: 0,
+            builder: GreenNodeBuilder::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn parse(mut self) -> (SyntaxNode, Vec<ParseError>) {
+        self.builder.start_node(SyntaxKind::Root.into());
+        while self.peek().is_some() {
+            let before = self.cursor;
+            self.parse_statement();
+            // A stray statement-boundary token (e.g. a leading `}`) is reported
+            // without being consumed; force progress so we never spin on it.
+            if self.cursor == before {
+                self.bump();
+            }
+        }
+        self.builder.finish_node();
+        let green: GreenNode = self.builder.finish();
+        (SyntaxNode::new_root(green), self.errors)
+    }
+
+    /// Peek at the next non-trivia token kind without consuming it.
+    fn peek(&self) -> Option<TokenKind> {
+        self.peek_nth(0)
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<TokenKind> {
+        self.tokens[self.cursor..]
+            .iter()
+            .filter(|t| !t.kind.is_trivia())
+            .nth(n)
+            .map(|t| t.kind)
+    }
+
+    /// Attach any pending trivia (whitespace/comments) to the tree verbatim so
+    /// the CST stays lossless.
+    fn eat_trivia(&mut self) {
+        while let Some(t) = self.tokens.get(self.cursor) {
+            if !t.kind.is_trivia() {
+                break;
+            }
+            self.bump_raw();
+        }
+    }
+
+    /// Consume the current (non-trivia) token into the tree.
+    fn bump(&mut self) {
+        self.eat_trivia();
+        self.bump_raw();
+    }
+
+    fn bump_raw(&mut self) {
+        if let Some(t) = self.tokens.get(self.cursor) {
+            self.builder.token(SyntaxKind::from(t.kind).into(), &t.text);
+            self.cursor += 1;
+        }
+    }
+
+    fn current_span(&self) -> Range<usize> {
+        self.tokens[self.cursor..]
+            .iter()
+            .find(|t| !t.kind.is_trivia())
+            .map(|t| t.span.clone())
+            .unwrap_or_else(|| {
+                let end = self.tokens.last().map(|t| t.span.end).unwrap_or(0);
+                end..end
+            })
+    }
+
+    /// Expect a specific token kind, emitting a diagnostic if it is missing.
+    fn expect(&mut self, kind: TokenKind) {
+        if self.peek() == Some(kind) {
+            self.bump();
+        } else {
+            self.error(vec![kind]);
+        }
+    }
+
+    /// Record an error at the current position and wrap the tokens up to the
+    /// next statement boundary (if any) in an `Error` node.
+    fn error(&mut self, expected: Vec<TokenKind>) {
+        self.errors.push(ParseError {
+            span: self.current_span(),
+            expected,
+            found: self.peek(),
+        });
+        if let Some(found) = self.peek() {
+            if !is_statement_boundary(found) {
+                self.builder.start_node(SyntaxKind::Error.into());
+                while let Some(kind) = self.peek() {
+                    if is_statement_boundary(kind) {
+                        break;
+                    }
+                    self.bump();
+                }
+                self.builder.finish_node();
+            }
+        }
+    }
+
+    fn parse_statement(&mut self) {
+        match self.peek() {
+            Some(TokenKind::ValKw) => self.parse_variable_def(),
+            _ => {
+                self.parse_expr(0);
+            }
+        }
+    }
+
+    fn parse_variable_def(&mut self) {
+        self.builder.start_node(SyntaxKind::VariableDef.into());
+        self.bump(); // `val`
+        self.expect(TokenKind::Ident);
+        if self.peek() == Some(TokenKind::Colon) {
+            self.bump();
+            self.parse_type();
+        }
+        self.expect(TokenKind::Equals);
+        self.parse_expr(0);
+        self.builder.finish_node();
+    }
+
+    /// A type ascription. We only need to capture its tokens losslessly here;
+    /// resolving them is the inference pass's job.
+    fn parse_type(&mut self) {
+        self.expect(TokenKind::Ident);
+        if self.peek() == Some(TokenKind::LBracket) {
+            self.bump();
+            self.parse_type();
+            while self.peek() == Some(TokenKind::Comma) {
+                self.bump();
+                self.parse_type();
+            }
+            self.expect(TokenKind::RBracket);
+        }
+    }
+
+    /// Precedence-climbing expression parser.
+    fn parse_expr(&mut self, min_bp: u8) {
+        let checkpoint = self.builder.checkpoint();
+        self.parse_lhs();
+        self.parse_postfix(checkpoint);
+
+        loop {
+            let op = match self.peek() {
+                Some(op) => op,
+                None => break,
+            };
+            let (left_bp, right_bp) = match infix_binding_power(op) {
+                Some(bp) => bp,
+                None => break,
+            };
+            if left_bp < min_bp {
+                break;
+            }
+            self.builder
+                .start_node_at(checkpoint, SyntaxKind::BinaryExpr.into());
+            self.bump(); // operator
+            self.parse_expr(right_bp);
+            self.builder.finish_node();
+        }
+    }
+
+    /// Parse the highest-precedence postfix chain — member access (`.name`) and
+    /// calls (`(args)`) — left-associatively onto the expression started at
+    /// `checkpoint`. This binds tighter than any infix operator, so `a.b(x)`
+    /// becomes a `Call` of the `a.b` access rather than `a.(b(x))`.
+    fn parse_postfix(&mut self, checkpoint: rowan::Checkpoint) {
+        loop {
+            match self.peek() {
+                Some(TokenKind::Dot) => {
+                    self.builder
+                        .start_node_at(checkpoint, SyntaxKind::BinaryExpr.into());
+                    self.bump(); // `.`
+                    // The member name is a bare identifier, not a full
+                    // expression, so a trailing call attaches to the whole
+                    // access on the next iteration.
+                    if self.peek() == Some(TokenKind::Ident) {
+                        self.builder.start_node(SyntaxKind::VariableRef.into());
+                        self.bump();
+                        self.builder.finish_node();
+                    } else {
+                        self.error(vec![TokenKind::Ident]);
+                    }
+                    self.builder.finish_node();
+                }
+                Some(TokenKind::LParen) => {
+                    self.builder
+                        .start_node_at(checkpoint, SyntaxKind::Call.into());
+                    self.parse_arg_list();
+                    self.builder.finish_node();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    /// Parse a parenthesised, comma-separated argument list into an `ArgList`.
+    fn parse_arg_list(&mut self) {
+        self.builder.start_node(SyntaxKind::ArgList.into());
+        self.expect(TokenKind::LParen);
+        while let Some(kind) = self.peek() {
+            if kind == TokenKind::RParen {
+                break;
+            }
+            self.parse_expr(0);
+            if self.peek() == Some(TokenKind::Comma) {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        self.expect(TokenKind::RParen);
+        self.builder.finish_node();
+    }
+
+    fn parse_lhs(&mut self) {
+        match self.peek() {
+            Some(
+                TokenKind::IntNumber
+                | TokenKind::LongNumber
+                | TokenKind::BigIntNumber
+                | TokenKind::ByteArray
+                | TokenKind::String
+                | TokenKind::TrueKw
+                | TokenKind::FalseKw,
+            ) => {
+                self.builder.start_node(SyntaxKind::Literal.into());
+                self.bump();
+                self.builder.finish_node();
+            }
+            Some(TokenKind::Ident) => {
+                self.builder.start_node(SyntaxKind::VariableRef.into());
+                self.bump();
+                self.builder.finish_node();
+            }
+            Some(op) if prefix_binding_power(op).is_some() => {
+                let right_bp = prefix_binding_power(op).unwrap();
+                self.builder.start_node(SyntaxKind::PrefixExpr.into());
+                self.bump();
+                self.parse_expr(right_bp);
+                self.builder.finish_node();
+            }
+            Some(TokenKind::LParen) => {
+                self.builder.start_node(SyntaxKind::ParenExpr.into());
+                self.bump();
+                self.parse_expr(0);
+                self.expect(TokenKind::RParen);
+                self.builder.finish_node();
+            }
+            Some(TokenKind::LBrace) => self.parse_block(),
+            Some(TokenKind::FnKw) => self.parse_lambda(),
+            Some(TokenKind::IfKw) => self.parse_if(),
+            _ => self.error(vec![
+                TokenKind::IntNumber,
+                TokenKind::Ident,
+                TokenKind::LParen,
+                TokenKind::LBrace,
+                TokenKind::IfKw,
+            ]),
+        }
+    }
+
+    fn parse_block(&mut self) {
+        self.builder.start_node(SyntaxKind::Block.into());
+        self.bump(); // `{`
+        while !matches!(self.peek(), Some(TokenKind::RBrace) | None) {
+            self.parse_statement();
+        }
+        self.expect(TokenKind::RBrace);
+        self.builder.finish_node();
+    }
+
+    fn parse_lambda(&mut self) {
+        self.builder.start_node(SyntaxKind::Lambda.into());
+        self.bump(); // `def`
+        self.builder.start_node(SyntaxKind::ParamList.into());
+        self.expect(TokenKind::LParen);
+        while !matches!(self.peek(), Some(TokenKind::RParen) | None) {
+            self.builder.start_node(SyntaxKind::Param.into());
+            self.expect(TokenKind::Ident);
+            if self.peek() == Some(TokenKind::Colon) {
+                self.bump();
+                self.parse_type();
+            }
+            self.builder.finish_node();
+            if self.peek() == Some(TokenKind::Comma) {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        self.expect(TokenKind::RParen);
+        self.builder.finish_node();
+        self.expect(TokenKind::FatArrow);
+        self.parse_expr(0);
+        self.builder.finish_node();
+    }
+
+    fn parse_if(&mut self) {
+        self.builder.start_node(SyntaxKind::IfExpr.into());
+        self.bump(); // `if`
+        self.expect(TokenKind::LParen);
+        self.parse_expr(0);
+        self.expect(TokenKind::RParen);
+        self.parse_expr(0);
+        if self.peek() == Some(TokenKind::ElseKw) {
+            self.bump();
+            self.parse_expr(0);
+        }
+        self.builder.finish_node();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Render the tree as an S-expression-ish dump for snapshot-style asserts.
+    fn dump(input: &str) -> (String, Vec<ParseError>) {
+        let (node, errors) = parse(input);
+        (format!("{:#?}", node), errors)
+    }
+
+    #[test]
+    fn parse_nothing() {
+        let (_, errors) = parse("");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn parse_number_literal() {
+        let (tree, errors) = dump("123");
+        assert!(errors.is_empty());
+        assert!(tree.contains("Literal"));
+        assert!(tree.contains("IntNumber"));
+    }
+
+    #[test]
+    fn parse_variable_ref() {
+        let (tree, errors) = dump("counter");
+        assert!(errors.is_empty());
+        assert!(tree.contains("VariableRef"));
+    }
+
+    #[test]
+    fn parse_binary_precedence() {
+        // `*` binds tighter than `+`, so the `+` must be the outermost node.
+        let (tree, errors) = dump("1 + 2 * 3");
+        assert!(errors.is_empty());
+        let first_binary = tree.find("BinaryExpr").unwrap();
+        let plus = tree.find("Plus").unwrap();
+        let star = tree.find("Star").unwrap();
+        // The nested `2 * 3` node opens after the `+` token.
+        let inner_binary =
+            first_binary + 1 + tree[first_binary + 1..].find("BinaryExpr").unwrap();
+        assert!(first_binary < plus && plus < inner_binary && inner_binary < star);
+    }
+
+    #[test]
+    fn parse_variable_def_with_ascription() {
+        let (tree, errors) = dump("val x: Int = 1 + 2");
+        assert!(errors.is_empty());
+        assert!(tree.contains("VariableDef"));
+        assert!(tree.contains("Colon"));
+    }
+
+    #[test]
+    fn parse_if_else() {
+        let (tree, errors) = dump("if (a && b) 1 else 2");
+        assert!(errors.is_empty());
+        assert!(tree.contains("IfExpr"));
+        assert!(tree.contains("ElseKw"));
+    }
+
+    #[test]
+    fn parse_block_with_val() {
+        let (tree, errors) = dump("{ val x = 1 x + 1 }");
+        assert!(errors.is_empty());
+        assert!(tree.contains("Block"));
+    }
+
+    #[test]
+    fn parse_lambda() {
+        let (tree, errors) = dump("def (x: Int) => x * x");
+        assert!(errors.is_empty());
+        assert!(tree.contains("Lambda"));
+        assert!(tree.contains("FatArrow"));
+    }
+
+    #[test]
+    fn parse_bitwise_and_shift_operators() {
+        // `|`, `^`, `>>`, `<<`, `>>>` are infix operators, not stray tokens.
+        for src in ["a | b", "a ^ b", "x >> 2", "x << 2", "x >>> 2"] {
+            let (tree, errors) = dump(src);
+            assert!(errors.is_empty(), "{src} produced {errors:?}");
+            assert!(tree.contains("BinaryExpr"), "{src} was not a binary expr");
+        }
+    }
+
+    #[test]
+    fn parse_method_call() {
+        // `coll.map(f)` is a call of the `coll.map` access, so the `Call` node
+        // encloses the `.`-access `BinaryExpr`.
+        let (tree, errors) = dump("coll.map(f)");
+        assert!(errors.is_empty());
+        let call = tree.find("Call").unwrap();
+        let binary = tree.find("BinaryExpr").unwrap();
+        assert!(call < binary);
+        assert!(tree.contains("ArgList"));
+    }
+
+    #[test]
+    fn recover_from_unexpected_token_and_keep_parsing() {
+        // The stray `)` is reported but the following `val` still parses.
+        let (tree, errors) = dump(")\nval x = 1");
+        assert!(!errors.is_empty());
+        assert!(tree.contains("VariableDef"));
+    }
+
+    #[test]
+    fn error_carries_expected_and_span() {
+        let (_, errors) = parse("(1 + 2");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].expected, vec![TokenKind::RParen]);
+    }
+}
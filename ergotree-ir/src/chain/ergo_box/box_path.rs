@@ -0,0 +1,256 @@
+//! Structured, path-addressed access into a box's registers and values.
+//!
+//! A [`BoxPath`] names a leaf inside an [`ErgoBox`] the way a filesystem path
+//! names a file: a [`BoxId`] root followed by `/`-separated selectors that step
+//! into a register, then into tuple components, collection elements and byte
+//! ranges. This gives tooling a uniform way to pull register data out of a box
+//! without hand-written decoding for every layout.
+use std::convert::TryFrom;
+
+use super::box_id::BoxId;
+use super::register::RegisterId;
+use super::ErgoBox;
+use crate::chain::digest32::Digest32Error;
+use crate::mir::value::Value;
+use crate::types::stype::SType;
+
+/// A single step of a [`BoxPath`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Selector {
+    /// A register `R0`–`R9`.
+    Register(RegisterId),
+    /// A tuple component or collection element by (zero-based) index.
+    Index(usize),
+    /// A half-open byte range `start..end` into a `Coll[Byte]`.
+    ByteRange(usize, usize),
+}
+
+/// A path rooted at a [`BoxId`], e.g. parsed from `"<boxId>/R4/2/0"`.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BoxPath {
+    /// The box this path addresses.
+    pub box_id: BoxId,
+    /// The selectors to walk, outermost first.
+    pub selectors: Vec<Selector>,
+}
+
+/// Error raised while parsing a [`BoxPath`] string.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum PathParseError {
+    /// The path was empty.
+    Empty,
+    /// The leading segment was not a valid box id.
+    InvalidBoxId(Digest32Error),
+    /// A selector segment could not be understood.
+    InvalidSelector(String),
+    /// A register segment was not one of `R0`–`R9`.
+    InvalidRegister(String),
+}
+
+impl From<Digest32Error> for PathParseError {
+    fn from(e: Digest32Error) -> Self {
+        PathParseError::InvalidBoxId(e)
+    }
+}
+
+/// Error raised while resolving a [`BoxPath`] against a box.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum PathError {
+    /// The addressed register held no value.
+    RegisterEmpty(RegisterId),
+    /// An index (or byte range) ran past the end of its container.
+    IndexOutOfBounds {
+        /// The index that was requested.
+        index: usize,
+        /// The length of the container.
+        len: usize,
+    },
+    /// A byte range whose `start` is greater than its `end`.
+    InvertedRange {
+        /// The requested start offset.
+        start: usize,
+        /// The requested end offset.
+        end: usize,
+    },
+    /// A selector tried to index into a value that is not indexable.
+    NotIndexable,
+    /// A byte range was applied to a collection that is not `Coll[Byte]`.
+    NotByteColl,
+    /// The first selector was not a register (paths must start at a register).
+    ExpectedRegister,
+}
+
+impl BoxPath {
+    /// Parse a path string of the form `"<boxId>/R4/2/0"`.
+    pub fn parse(s: &str) -> Result<Self, PathParseError> {
+        let mut segments = s.split('/');
+        let head = segments.next().ok_or(PathParseError::Empty)?;
+        if head.is_empty() {
+            return Err(PathParseError::Empty);
+        }
+        let box_id = BoxId::try_from(head.to_string())?;
+        let selectors = segments
+            .map(Selector::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(BoxPath { box_id, selectors })
+    }
+
+    /// Resolve this path against `ergo_box`, returning the addressed leaf value.
+    pub fn resolve(&self, ergo_box: &ErgoBox) -> Result<Value, PathError> {
+        let mut selectors = self.selectors.iter();
+        let first = selectors.next().ok_or(PathError::ExpectedRegister)?;
+        let reg_id = match first {
+            Selector::Register(id) => *id,
+            _ => return Err(PathError::ExpectedRegister),
+        };
+        let mut value = ergo_box
+            .get_register(reg_id)
+            .ok_or(PathError::RegisterEmpty(reg_id))?
+            .v;
+        for selector in selectors {
+            value = step(value, selector)?;
+        }
+        Ok(value)
+    }
+
+    /// Whether `self` is a prefix of `other` (same box, and every selector of
+    /// `self` matches the corresponding selector of `other`).
+    ///
+    /// Callers use this to enumerate everything under a partial path — e.g. all
+    /// components of `R4` — mirroring the `get_prefix` query style.
+    pub fn prefix_matches(&self, other: &BoxPath) -> bool {
+        self.box_id == other.box_id
+            && self.selectors.len() <= other.selectors.len()
+            && self
+                .selectors
+                .iter()
+                .zip(other.selectors.iter())
+                .all(|(a, b)| a == b)
+    }
+}
+
+impl Selector {
+    fn parse(segment: &str) -> Result<Selector, PathParseError> {
+        if let Some(rest) = segment.strip_prefix('R') {
+            let n: u8 = rest
+                .parse()
+                .map_err(|_| PathParseError::InvalidRegister(segment.to_string()))?;
+            return RegisterId::try_from(n)
+                .map(Selector::Register)
+                .map_err(|_| PathParseError::InvalidRegister(segment.to_string()));
+        }
+        if let Some((start, end)) = segment.split_once("..") {
+            let start = start
+                .parse()
+                .map_err(|_| PathParseError::InvalidSelector(segment.to_string()))?;
+            let end = end
+                .parse()
+                .map_err(|_| PathParseError::InvalidSelector(segment.to_string()))?;
+            return Ok(Selector::ByteRange(start, end));
+        }
+        segment
+            .parse()
+            .map(Selector::Index)
+            .map_err(|_| PathParseError::InvalidSelector(segment.to_string()))
+    }
+}
+
+/// Step a single selector into `value`.
+fn step(value: Value, selector: &Selector) -> Result<Value, PathError> {
+    match selector {
+        Selector::Register(_) => Err(PathError::NotIndexable),
+        Selector::Index(i) => index_into(value, *i),
+        Selector::ByteRange(start, end) => byte_range(value, *start, *end),
+    }
+}
+
+/// Index into a tuple component or collection element.
+fn index_into(value: Value, i: usize) -> Result<Value, PathError> {
+    match value {
+        Value::Tup(items) => {
+            let len = items.len();
+            items
+                .into_iter()
+                .nth(i)
+                .ok_or(PathError::IndexOutOfBounds { index: i, len })
+        }
+        Value::Coll(coll) => {
+            let items = coll.as_vec();
+            let len = items.len();
+            items
+                .into_iter()
+                .nth(i)
+                .ok_or(PathError::IndexOutOfBounds { index: i, len })
+        }
+        _ => Err(PathError::NotIndexable),
+    }
+}
+
+/// Slice a `Coll[Byte]` into the half-open range `start..end`.
+fn byte_range(value: Value, start: usize, end: usize) -> Result<Value, PathError> {
+    match value {
+        Value::Coll(coll) => {
+            // `ByteRange` is scoped to `Coll[Byte]`; reject other element types
+            // rather than silently slicing them.
+            if coll.elem_tpe() != &SType::SByte {
+                return Err(PathError::NotByteColl);
+            }
+            let items = coll.as_vec();
+            let len = items.len();
+            if start > end {
+                return Err(PathError::InvertedRange { start, end });
+            }
+            if end > len {
+                return Err(PathError::IndexOutOfBounds { index: end, len });
+            }
+            Ok(Value::Coll(coll.slice(start, end)))
+        }
+        _ => Err(PathError::NotIndexable),
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_register_and_indices() {
+        let box_id = BoxId::zero();
+        let s = format!("{}/R4/2/0", String::from(box_id.clone()));
+        let path = BoxPath::parse(&s).unwrap();
+        assert_eq!(path.box_id, box_id);
+        assert_eq!(
+            path.selectors,
+            vec![
+                Selector::Register(RegisterId::try_from(4u8).unwrap()),
+                Selector::Index(2),
+                Selector::Index(0),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_byte_range() {
+        let s = format!("{}/R5/0..4", String::from(BoxId::zero()));
+        let path = BoxPath::parse(&s).unwrap();
+        assert_eq!(path.selectors.last(), Some(&Selector::ByteRange(0, 4)));
+    }
+
+    #[test]
+    fn parse_rejects_bad_register() {
+        let s = format!("{}/Rx", String::from(BoxId::zero()));
+        assert!(matches!(
+            BoxPath::parse(&s),
+            Err(PathParseError::InvalidRegister(_))
+        ));
+    }
+
+    #[test]
+    fn prefix_matches_enumerates_under_register() {
+        let root = BoxPath::parse(&format!("{}/R4", String::from(BoxId::zero()))).unwrap();
+        let leaf = BoxPath::parse(&format!("{}/R4/2", String::from(BoxId::zero()))).unwrap();
+        assert!(root.prefix_matches(&leaf));
+        assert!(!leaf.prefix_matches(&root));
+    }
+}